@@ -0,0 +1,203 @@
+use std::ops::Index;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use na;
+use math::{Point, Vector};
+use entities::bounding_volume::AABB;
+
+/// One endpoint (either the min or the max) of a proxy's AABB along the swept axis.
+#[derive(Clone)]
+struct Endpoint<N> {
+    value:   N,
+    proxy:   usize,
+    is_min:  bool
+}
+
+struct SAPProxy<P: Point, T> {
+    aabb: AABB<P>,
+    data: T
+}
+
+/// An incremental sweep-and-prune broad phase.
+///
+/// Candidate overlapping pairs are maintained by sorting each proxy's AABB endpoints
+/// along a single axis and tracking the crossings that occur as that order changes from
+/// one `update` to the next. Because object motion is usually coherent from one frame to
+/// the next, the sorted list stays nearly-sorted and an insertion sort settles it in
+/// close to linear time. The swept axis is re-picked at every `update` as the axis along
+/// which the proxies' centers have the highest variance, since that is the axis most
+/// likely to separate them and thus yields the fewest false-positive pairs.
+pub struct SweepAndPrune<P: Point, T> {
+    proxies:   Vec<SAPProxy<P, T>>,
+    handles:   HashMap<T, usize>,
+    endpoints: Vec<Endpoint<<P::Vect as Vector>::Scalar>>,
+    axis:      usize,
+    pairs:     HashSet<(usize, usize)>
+}
+
+impl<P, T> SweepAndPrune<P, T>
+    where P: Point,
+          T: Clone + Eq + Hash,
+          P::Vect: Index<usize, Output = <P::Vect as Vector>::Scalar> {
+    /// Creates a new, empty sweep-and-prune broad phase.
+    pub fn new() -> SweepAndPrune<P, T> {
+        SweepAndPrune {
+            proxies:   Vec::new(),
+            handles:   HashMap::new(),
+            endpoints: Vec::new(),
+            axis:      0,
+            pairs:     HashSet::new()
+        }
+    }
+
+    /// Registers a new proxy with the given user-provided `handle` and initial `aabb`.
+    ///
+    /// If `handle` is already registered, its AABB is updated instead.
+    pub fn create_proxy(&mut self, handle: T, aabb: AABB<P>) {
+        if self.handles.contains_key(&handle) {
+            self.update_proxy(handle, aabb);
+            return;
+        }
+
+        let id = self.proxies.len();
+        self.proxies.push(SAPProxy { aabb: aabb.clone(), data: handle.clone() });
+        self.handles.insert(handle, id);
+
+        self.endpoints.push(Endpoint { value: aabb.mins()[self.axis], proxy: id, is_min: true });
+        self.endpoints.push(Endpoint { value: aabb.maxs()[self.axis], proxy: id, is_min: false });
+    }
+
+    /// Updates the AABB associated to `handle`, if it is registered.
+    pub fn update_proxy(&mut self, handle: T, aabb: AABB<P>) {
+        if let Some(&id) = self.handles.get(&handle) {
+            self.proxies[id].aabb = aabb;
+        }
+    }
+
+    /// Removes the proxy associated to `handle`, if it is registered.
+    pub fn remove_proxy(&mut self, handle: T) {
+        if let Some(id) = self.handles.remove(&handle) {
+            self.endpoints.retain(|e| e.proxy != id);
+            self.pairs.retain(|&(a, b)| a != id && b != id);
+
+            // Swap-remove the proxy and patch the endpoints/pairs referencing the proxy
+            // that used to sit at the end of the vector.
+            let last = self.proxies.len() - 1;
+            self.proxies.swap_remove(id);
+
+            if id != last {
+                let moved_handle = self.proxies[id].data.clone();
+                self.handles.insert(moved_handle, id);
+
+                for e in &mut self.endpoints {
+                    if e.proxy == last {
+                        e.proxy = id;
+                    }
+                }
+
+                let moved: Vec<_> = self.pairs.iter().cloned()
+                    .filter(|&(a, b)| a == last || b == last)
+                    .collect();
+
+                for (a, b) in moved {
+                    self.pairs.remove(&(a, b));
+                    let a = if a == last { id } else { a };
+                    let b = if b == last { id } else { b };
+                    self.pairs.insert(if a < b { (a, b) } else { (b, a) });
+                }
+            }
+        }
+    }
+
+    /// Re-sorts the swept axis' endpoints and refreshes the set of overlapping pairs.
+    ///
+    /// This must be called once per frame after all proxies have been updated.
+    pub fn update(&mut self) {
+        self.select_axis();
+
+        for e in &mut self.endpoints {
+            let aabb = &self.proxies[e.proxy].aabb;
+            e.value = if e.is_min { aabb.mins()[self.axis] } else { aabb.maxs()[self.axis] };
+        }
+
+        // Insertion sort: nearly-sorted input (temporal coherence) makes this close to
+        // O(n), unlike a full re-sort every frame.
+        for i in 1..self.endpoints.len() {
+            let mut j = i;
+
+            while j > 0 && self.endpoints[j - 1].value > self.endpoints[j].value {
+                let (a, b) = (self.endpoints[j - 1].proxy, self.endpoints[j].proxy);
+
+                if self.endpoints[j - 1].is_min != self.endpoints[j].is_min && a != b {
+                    // A min moving left past another proxy's max starts their overlap
+                    // along the swept axis; a max moving left past a min ends it.
+                    let starts = self.endpoints[j].is_min;
+                    self.toggle_pair(a, b, starts);
+                }
+
+                self.endpoints.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+
+    /// The set of proxy handle pairs whose AABBs currently overlap.
+    pub fn pairs(&self) -> Vec<(T, T)> {
+        // `self.pairs` holds the pairs overlapping along the swept axis; keep those
+        // that overlap along the other axes as well.
+        self.pairs.iter()
+            .filter(|&&(a, b)| aabbs_overlap(&self.proxies[a].aabb, &self.proxies[b].aabb))
+            .map(|&(a, b)| (self.proxies[a].data.clone(), self.proxies[b].data.clone()))
+            .collect()
+    }
+
+    fn toggle_pair(&mut self, a: usize, b: usize, overlapping: bool) {
+        let key = if a < b { (a, b) } else { (b, a) };
+
+        if overlapping {
+            self.pairs.insert(key);
+        } else {
+            self.pairs.remove(&key);
+        }
+    }
+
+    fn select_axis(&mut self) {
+        let dim = na::dimension::<P::Vect>();
+        let n = self.proxies.len();
+
+        if n == 0 {
+            return;
+        }
+
+        let mut best_axis = 0;
+        let mut best_variance = na::zero();
+
+        for axis in 0..dim {
+            let mut mean = na::zero();
+
+            for p in &self.proxies {
+                mean = mean + p.aabb.center().to_vector()[axis];
+            }
+
+            mean = mean / na::convert(n as f64);
+
+            let mut variance = na::zero();
+
+            for p in &self.proxies {
+                let diff = p.aabb.center().to_vector()[axis] - mean;
+                variance = variance + diff * diff;
+            }
+
+            if variance > best_variance {
+                best_variance = variance;
+                best_axis = axis;
+            }
+        }
+
+        self.axis = best_axis;
+    }
+}
+
+fn aabbs_overlap<P: Point>(a: &AABB<P>, b: &AABB<P>) -> bool {
+    a.intersects(b)
+}
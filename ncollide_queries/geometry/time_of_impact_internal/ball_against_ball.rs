@@ -0,0 +1,52 @@
+use na;
+use math::{Point, Vector};
+use entities::shape::Ball;
+use geometry::time_of_impact_internal::{ShapeCastOptions, ShapeCastHit};
+
+/// Time of impact of two balls under translational motion.
+pub fn ball_against_ball<P>(center1: &P, vel1: &P::Vect, b1: &Ball<<P::Vect as Vector>::Scalar>,
+                            center2: &P, vel2: &P::Vect, b2: &Ball<<P::Vect as Vector>::Scalar>,
+                            options: &ShapeCastOptions<<P::Vect as Vector>::Scalar>)
+                            -> Option<ShapeCastHit<P>>
+    where P: Point {
+    let dpos = *center2 - *center1;
+    let dvel = *vel2 - *vel1;
+    let sum_radii = b1.radius() + b2.radius() + options.target_distance;
+
+    let c = na::norm_squared(&dpos) - sum_radii * sum_radii;
+
+    if c <= na::zero() && options.stop_at_penetration {
+        return Some(ShapeCastHit {
+            time_of_impact: na::zero(),
+            witness1: *center1,
+            witness2: *center2,
+            normal: na::zero()
+        });
+    }
+
+    let a = na::norm_squared(&dvel);
+
+    if a <= na::zero() {
+        // No relative motion: the balls will never reach `sum_radii` of each other.
+        return None;
+    }
+
+    let b = na::dot(&dpos, &dvel);
+    let discriminant = b * b - a * c;
+
+    if discriminant < na::zero() {
+        return None;
+    }
+
+    let t = (-b - discriminant.sqrt()) / a;
+
+    if t < na::zero() || t > options.max_time_of_impact {
+        return None;
+    }
+
+    let n = na::normalize(&(dpos + dvel * t));
+    let witness1 = *center1 + *vel1 * t + n * b1.radius();
+    let witness2 = *center2 + *vel2 * t - n * b2.radius();
+
+    Some(ShapeCastHit { time_of_impact: t, witness1, witness2, normal: n })
+}
@@ -0,0 +1,148 @@
+use std::marker::PhantomData;
+use na::{Identity, Translate, Translation};
+use na;
+use entities::bounding_volume::{self, HasBoundingVolume, AABB};
+use entities::partitioning::BVTCostFn;
+use entities::shape::CompositeShape;
+use entities::inspection::Shape;
+use point::PointQuery;
+use geometry::time_of_impact_internal;
+use geometry::time_of_impact_internal::{ShapeCastOptions, ShapeCastHit};
+use math::{Point, Vector, Isometry};
+
+/// Smallest time of impact of a composite shape and any other shape.
+pub fn composite_shape_against_any<P, M, G1: ?Sized, G2: ?Sized>(m1: &M, vel1: &P::Vect, avel1: &P::Vect, g1: &G1,
+                                                                 m2: &M, vel2: &P::Vect, avel2: &P::Vect, g2: &G2,
+                                                                 options: &ShapeCastOptions<<P::Vect as Vector>::Scalar>)
+                                                                 -> Option<ShapeCastHit<P>>
+    where P:  Point,
+          P::Vect: Translate<P>,
+          M:  Isometry<P, P::Vect> + Translation<P::Vect>,
+          G1: CompositeShape<P, M>,
+          G2: Shape<P, M> + HasBoundingVolume<M, AABB<P>> {
+    let mut cost_fn = CompositeShapeAgainstAnyTOICostFn::new(m1, vel1, avel1, g1, m2, vel2, avel2, g2, options);
+
+    g1.bvt().best_first_search(&mut cost_fn).map(|(_, res)| res)
+}
+
+/// Smallest time of impact of a shape and a composite shape.
+pub fn any_against_composite_shape<P, M, G1: ?Sized, G2: ?Sized>(m1: &M, vel1: &P::Vect, avel1: &P::Vect, g1: &G1,
+                                                                 m2: &M, vel2: &P::Vect, avel2: &P::Vect, g2: &G2,
+                                                                 options: &ShapeCastOptions<<P::Vect as Vector>::Scalar>)
+                                                                 -> Option<ShapeCastHit<P>>
+    where P:  Point,
+          P::Vect: Translate<P>,
+          M:  Isometry<P, P::Vect> + Translation<P::Vect>,
+          G1: Shape<P, M> + HasBoundingVolume<M, AABB<P>>,
+          G2: CompositeShape<P, M> {
+    composite_shape_against_any(m2, vel2, avel2, g2, m1, vel1, avel1, g1, options)
+        .map(|hit| ShapeCastHit {
+            time_of_impact: hit.time_of_impact,
+            witness1: hit.witness2,
+            witness2: hit.witness1,
+            normal: -hit.normal
+        })
+}
+
+struct CompositeShapeAgainstAnyTOICostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized + 'a, G2: ?Sized + 'a> {
+    msum_shift:  P::Vect,
+    msum_margin: P::Vect,
+
+    m1:      &'a M,
+    vel1:    &'a P::Vect,
+    avel1:   &'a P::Vect,
+    g1:      &'a G1,
+    m2:      &'a M,
+    vel2:    &'a P::Vect,
+    avel2:   &'a P::Vect,
+    g2:      &'a G2,
+    options: &'a ShapeCastOptions<<P::Vect as Vector>::Scalar>,
+
+    point_type: PhantomData<P>
+}
+
+impl<'a, P, M, G1: ?Sized, G2: ?Sized> CompositeShapeAgainstAnyTOICostFn<'a, P, M, G1, G2>
+    where P:  Point,
+          M:  Isometry<P, P::Vect>,
+          G1: CompositeShape<P, M>,
+          G2: Shape<P, M> + HasBoundingVolume<M, AABB<P>> {
+    pub fn new(m1: &'a M, vel1: &'a P::Vect, avel1: &'a P::Vect, g1: &'a G1,
+               m2: &'a M, vel2: &'a P::Vect, avel2: &'a P::Vect, g2: &'a G2,
+               options: &'a ShapeCastOptions<<P::Vect as Vector>::Scalar>)
+               -> CompositeShapeAgainstAnyTOICostFn<'a, P, M, G1, G2> {
+        let ls_m2 = na::inverse(m1).expect("The transformation `m1` must be inversible.") * *m2;
+        let ls_aabb2 = bounding_volume::aabb(g2, &ls_m2);
+
+        CompositeShapeAgainstAnyTOICostFn {
+            msum_shift:  -ls_aabb2.center().to_vector(),
+            msum_margin: ls_aabb2.half_extents(),
+            m1:          m1,
+            vel1:        vel1,
+            avel1:       avel1,
+            g1:          g1,
+            m2:          m2,
+            vel2:        vel2,
+            avel2:       avel2,
+            g2:          g2,
+            options:     options,
+            point_type:  PhantomData
+        }
+    }
+}
+
+impl<'a, P, M, G1: ?Sized, G2: ?Sized>
+BVTCostFn<<P::Vect as Vector>::Scalar, usize, AABB<P>>
+for CompositeShapeAgainstAnyTOICostFn<'a, P, M, G1, G2>
+    where P:  Point,
+          P::Vect: Translate<P>,
+          M:  Isometry<P, P::Vect> + Translation<P::Vect>,
+          G1: CompositeShape<P, M>,
+          G2: Shape<P, M> + HasBoundingVolume<M, AABB<P>> {
+    type UserData = ShapeCastHit<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<<P::Vect as Vector>::Scalar> {
+        // Lower-bound the time of impact for this subtree the same way the distance
+        // query lower-bounds the distance: by the distance, in the relative motion's
+        // frame, between the Minkowski sum of `bv` and `g2`'s AABB and the origin, less
+        // `target_distance`, over the linear closing speed.
+        //
+        // An individual subtree's AABB has no single center of rotation to bound spin
+        // against, so when either body spins the only bound available here is `0`: every
+        // subtree is then visited and `compute_b_cost` evaluates the angular motion part
+        // by part.
+        let msum = AABB::new(*bv.mins() + self.msum_shift + (-self.msum_margin),
+                             *bv.maxs() + self.msum_shift + self.msum_margin);
+
+        let d = msum.distance_to_point(&Identity::new(), &na::origin(), true);
+        let gap = if d > self.options.target_distance { d - self.options.target_distance } else { na::zero() };
+
+        if na::norm_squared(self.avel1) > na::zero() || na::norm_squared(self.avel2) > na::zero() {
+            return Some(na::zero());
+        }
+
+        let closing_speed = na::norm(&(*self.vel2 - *self.vel1));
+
+        if closing_speed <= na::zero() {
+            if gap <= na::zero() { Some(na::zero()) } else { None }
+        }
+        else {
+            Some(gap / closing_speed)
+        }
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(<P::Vect as Vector>::Scalar, ShapeCastHit<P>)> {
+        let mut res = None;
+
+        self.g1.map_transformed_part_at(*b, self.m1, &mut |m1, g1| {
+            if let Some(hit) = time_of_impact_internal::any_against_any(m1, self.vel1, self.avel1, g1,
+                                                                        self.m2, self.vel2, self.avel2, self.g2,
+                                                                        self.options) {
+                res = Some((hit.time_of_impact, hit));
+            }
+        });
+
+        res
+    }
+}
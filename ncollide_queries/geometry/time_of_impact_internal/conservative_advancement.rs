@@ -0,0 +1,123 @@
+use na::{Translate, Translation, Rotation};
+use na;
+use math::{Point, Vector, Isometry};
+use entities::shape::SupportMap;
+use entities::bounding_volume::{HasBoundingVolume, BoundingSphere};
+use geometry::closest_points_internal;
+use geometry::closest_points_internal::Closest;
+use geometry::time_of_impact_internal::{ShapeCastOptions, ShapeCastHit};
+
+/// Computes the time of impact between two support-mapped shapes undergoing both linear
+/// and angular motion, using conservative advancement on top of GJK.
+///
+/// `avel1` and `avel2` are the bodies' angular velocities, expressed in the same vector
+/// space as their linear counterparts. Returns `None` if the shapes are found to be
+/// separating, or if no impact occurs within `options`.
+pub fn nonlinear_time_of_impact<P, M, G1: ?Sized, G2: ?Sized>(m1: &M, vel1: &P::Vect, avel1: &P::Vect, g1: &G1,
+                                                              m2: &M, vel2: &P::Vect, avel2: &P::Vect, g2: &G2,
+                                                              options: &ShapeCastOptions<<P::Vect as Vector>::Scalar>)
+                                                              -> Option<ShapeCastHit<P>>
+    where P:  Point,
+          P::Vect: Translate<P>,
+          M:  Isometry<P, P::Vect> + Translation<P::Vect> + Rotation<P::Vect>,
+          G1: SupportMap<P, M> + HasBoundingVolume<M, BoundingSphere<P>>,
+          G2: SupportMap<P, M> + HasBoundingVolume<M, BoundingSphere<P>> {
+    let r1 = g1.bounding_volume(m1).radius();
+    let r2 = g2.bounding_volume(m2).radius();
+    let w1 = na::norm(avel1);
+    let w2 = na::norm(avel2);
+
+    let mut t = na::zero();
+
+    loop {
+        if t > options.max_time_of_impact {
+            return None;
+        }
+
+        let curr_m1 = interpolate(m1, vel1, avel1, t);
+        let curr_m2 = interpolate(m2, vel2, avel2, t);
+
+        // An unbounded margin, so that GJK reports the closest points of separated shapes
+        // whatever their distance: the advancement step below needs them.
+        let margin: <P::Vect as Vector>::Scalar = na::convert(::std::f64::INFINITY);
+
+        let (p1, p2) = match closest_points_internal::support_map_against_support_map(&curr_m1, g1, &curr_m2, g2, margin) {
+            Closest::Intersection if options.stop_at_penetration => {
+                // There's no separating GJK simplex to read witness points off of while
+                // overlapping; approximate them with each shape's support point along the
+                // first shape's velocity relative to the second (which, for approaching
+                // shapes, points from the first toward the second), so callers still get
+                // points on the surfaces (in world space) instead of the bodies' centers.
+                let rel_vel = *vel1 - *vel2;
+                let rel_speed = na::norm(&rel_vel);
+                let n = if rel_speed > na::zero() { rel_vel / rel_speed } else { na::zero() };
+
+                return Some(ShapeCastHit {
+                    time_of_impact: t,
+                    witness1: g1.support_point(&curr_m1, &n),
+                    witness2: g2.support_point(&curr_m2, &-n),
+                    normal: n
+                });
+            }
+            Closest::Intersection => {
+                // The caller asked us to look past the initial overlap: keep advancing
+                // as if the shapes were merely touching, so we can find the time at
+                // which they separate and come back into contact. There is no
+                // well-defined separating direction while overlapping, so `target_distance`
+                // alone can't be trusted to make progress (it defaults to `0.0`); fall
+                // back to a fixed minimum step in that case.
+                let min_step: <P::Vect as Vector>::Scalar = na::convert(1.0e-4f64);
+                let step = if options.target_distance > min_step { options.target_distance } else { min_step };
+
+                t = t + step;
+                continue;
+            }
+            Closest::WithinMargin(p1, p2) => (p1, p2),
+            Closest::Disjoint => return None
+        };
+
+        let dir = p2 - p1;
+        let d = na::norm(&dir);
+
+        if d <= options.target_distance {
+            let n = if d > na::zero() { dir / d } else { na::zero() };
+
+            return Some(ShapeCastHit {
+                time_of_impact: t,
+                witness1: p1,
+                witness2: p2,
+                normal: n
+            });
+        }
+
+        let n = dir / d;
+        let mu = na::dot(&(*vel2 - *vel1), &n) + w1 * r1 + w2 * r2;
+
+        if mu <= na::zero() {
+            // The shapes are not getting any closer.
+            return None;
+        }
+
+        t = t + (d - options.target_distance) / mu;
+    }
+}
+
+/// Re-interpolates an isometry at time `t` given its linear and angular velocities.
+///
+/// The translation is interpolated linearly while the rotation is interpolated as a
+/// constant-speed spin about the body's own origin, matching the motion a rigid body
+/// with constant `vel`/`avel` would follow.
+fn interpolate<P, M>(m: &M, vel: &P::Vect, avel: &P::Vect, t: <P::Vect as Vector>::Scalar) -> M
+    where P: Point,
+          M: Isometry<P, P::Vect> + Translation<P::Vect> + Rotation<P::Vect> {
+    let mut res = m.clone();
+    let center = res.translation();
+
+    // Appending a rotation also rotates the translation about the world origin, so
+    // take the translation out first and put it back, advanced, afterwards.
+    res.append_translation_mut(&-center);
+    res.append_rotation_mut(&(*avel * t));
+    res.append_translation_mut(&(center + *vel * t));
+
+    res
+}
@@ -0,0 +1,111 @@
+use na::{Translate, Translation, Rotate, Rotation};
+use na;
+use math::{Point, Vector, Isometry};
+use entities::shape::{Plane, SupportMap};
+use entities::bounding_volume::{HasBoundingVolume, BoundingSphere};
+use geometry::time_of_impact_internal::{ShapeCastOptions, ShapeCastHit};
+
+/// Time of impact of a plane and a support-mapped shape under linear and angular motion.
+///
+/// The plane itself is assumed not to spin (`avel_plane` isn't a meaningful quantity for
+/// an infinite half-space), but `avel_other` is accounted for by conservatively bounding
+/// how fast it can bring `other`'s support point toward the plane, using its bounding
+/// sphere radius about its center of rotation.
+pub fn plane_against_support_map<P, M, G: ?Sized>(mplane: &M, vel_plane: &P::Vect, plane: &Plane<P::Vect>,
+                                                  mother: &M, vel_other: &P::Vect, avel_other: &P::Vect, other: &G,
+                                                  options: &ShapeCastOptions<<P::Vect as Vector>::Scalar>)
+                                                  -> Option<ShapeCastHit<P>>
+    where P: Point,
+          P::Vect: Translate<P>,
+          M: Isometry<P, P::Vect> + Translation<P::Vect> + Rotation<P::Vect> + Rotate<P::Vect>,
+          G: SupportMap<P, M> + HasBoundingVolume<M, BoundingSphere<P>> {
+    let normal = mplane.rotate(plane.normal());
+    let plane_center = mplane.translate(&na::origin());
+
+    let r_other = other.bounding_volume(mother).radius();
+    let w_other = na::norm(avel_other);
+
+    let mut t = na::zero();
+
+    loop {
+        if t > options.max_time_of_impact {
+            return None;
+        }
+
+        let curr_mother = interpolate(mother, vel_other, avel_other, t);
+        let neg_normal = -normal;
+        let deepest = other.support_point(&curr_mother, &neg_normal);
+        let plane_pos = plane_center + *vel_plane * t;
+
+        let dist = na::dot(&(deepest - plane_pos), &normal) - options.target_distance;
+
+        if dist <= na::zero() {
+            if options.stop_at_penetration {
+                return Some(ShapeCastHit {
+                    time_of_impact: t,
+                    witness1: plane_pos,
+                    witness2: deepest,
+                    normal
+                });
+            }
+
+            let min_step: <P::Vect as Vector>::Scalar = na::convert(1.0e-4f64);
+            let step = if options.target_distance > min_step { options.target_distance } else { min_step };
+
+            t = t + step;
+            continue;
+        }
+
+        let closing_vel = na::dot(&(*vel_plane - *vel_other), &normal) + w_other * r_other;
+
+        if closing_vel <= na::zero() {
+            // The plane and the shape are not getting any closer along the plane's normal.
+            return None;
+        }
+
+        t = t + dist / closing_vel;
+
+        if t > options.max_time_of_impact {
+            return None;
+        }
+
+        let final_mother = interpolate(mother, vel_other, avel_other, t);
+        let witness2 = other.support_point(&final_mother, &neg_normal);
+        let witness1 = plane_center + *vel_plane * t;
+
+        return Some(ShapeCastHit { time_of_impact: t, witness1, witness2, normal });
+    }
+}
+
+/// Time of impact of a support-mapped shape and a plane under linear and angular motion.
+pub fn support_map_against_plane<P, M, G: ?Sized>(mother: &M, vel_other: &P::Vect, avel_other: &P::Vect, other: &G,
+                                                  mplane: &M, vel_plane: &P::Vect, plane: &Plane<P::Vect>,
+                                                  options: &ShapeCastOptions<<P::Vect as Vector>::Scalar>)
+                                                  -> Option<ShapeCastHit<P>>
+    where P: Point,
+          P::Vect: Translate<P>,
+          M: Isometry<P, P::Vect> + Translation<P::Vect> + Rotation<P::Vect> + Rotate<P::Vect>,
+          G: SupportMap<P, M> + HasBoundingVolume<M, BoundingSphere<P>> {
+    plane_against_support_map(mplane, vel_plane, plane, mother, vel_other, avel_other, other, options)
+        .map(|hit| ShapeCastHit {
+            time_of_impact: hit.time_of_impact,
+            witness1: hit.witness2,
+            witness2: hit.witness1,
+            normal: -hit.normal
+        })
+}
+
+fn interpolate<P, M>(m: &M, vel: &P::Vect, avel: &P::Vect, t: <P::Vect as Vector>::Scalar) -> M
+    where P: Point,
+          M: Isometry<P, P::Vect> + Translation<P::Vect> + Rotation<P::Vect> {
+    let mut res = m.clone();
+    let center = res.translation();
+
+    // Spin about the body's own origin: appending a rotation also rotates the
+    // translation about the world origin, so take it out first and put it back after.
+    res.append_translation_mut(&-center);
+    res.append_rotation_mut(&(*avel * t));
+    res.append_translation_mut(&(center + *vel * t));
+
+    res
+}
@@ -0,0 +1,49 @@
+use math::Point;
+
+/// Configuration for a time-of-impact (shape-cast) query.
+#[derive(Clone)]
+pub struct ShapeCastOptions<N> {
+    /// The maximum time of impact that should be computed.
+    ///
+    /// Impacts occurring strictly after this time are ignored and the query returns `None`.
+    pub max_time_of_impact: N,
+    /// The shapes are considered to be touching as soon as their separation falls below
+    /// this distance, instead of waiting for an exact geometric contact.
+    ///
+    /// This effectively grants both shapes a collision margin/skin, which is useful for
+    /// CCD callers that don't want to re-scale their geometry to get breathing room.
+    pub target_distance: N,
+    /// If `false`, and the shapes are already penetrating at `t = 0`, the query keeps
+    /// advancing instead of immediately returning a time of impact of `0.0`.
+    ///
+    /// This searches for the first time at which the shapes are separating and then come
+    /// back into (touching) contact, which is typically what's desired when the initial
+    /// overlap is itself the result of numerical error rather than an actual collision.
+    pub stop_at_penetration: bool
+}
+
+impl<N: ::na::Real> ShapeCastOptions<N> {
+    /// Creates the default set of options: a time limit of `1.0e10` (in practice none),
+    /// no target distance, and
+    /// stopping immediately on initial penetration.
+    pub fn new() -> ShapeCastOptions<N> {
+        ShapeCastOptions {
+            max_time_of_impact: ::na::convert(1.0e10f64),
+            target_distance: ::na::zero(),
+            stop_at_penetration: true
+        }
+    }
+}
+
+/// The result of a time-of-impact (shape-cast) query.
+#[derive(Clone)]
+pub struct ShapeCastHit<P: Point> {
+    /// The time at which the shapes reach `target_distance` of each other.
+    pub time_of_impact: <P::Vect as ::math::Vector>::Scalar,
+    /// The witness point on the first shape, expressed in world space at the impact time.
+    pub witness1: P,
+    /// The witness point on the second shape, expressed in world space at the impact time.
+    pub witness2: P,
+    /// The contact normal, pointing from the first shape toward the second.
+    pub normal: P::Vect
+}
@@ -0,0 +1,22 @@
+use na::{Translate, Translation, Rotation};
+use math::{Point, Vector, Isometry};
+use entities::shape::SupportMap;
+use entities::bounding_volume::{HasBoundingVolume, BoundingSphere};
+use geometry::time_of_impact_internal::{ShapeCastOptions, ShapeCastHit};
+use geometry::time_of_impact_internal::conservative_advancement;
+
+/// Time of impact of two support-mapped shapes under linear and angular motion.
+///
+/// This is just `conservative_advancement::nonlinear_time_of_impact`; it exists so the
+/// dispatcher has one name per shape-pair, the same way `support_map_against_plane` does.
+pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(m1: &M, vel1: &P::Vect, avel1: &P::Vect, g1: &G1,
+                                                                     m2: &M, vel2: &P::Vect, avel2: &P::Vect, g2: &G2,
+                                                                     options: &ShapeCastOptions<<P::Vect as Vector>::Scalar>)
+                                                                     -> Option<ShapeCastHit<P>>
+    where P:  Point,
+          P::Vect: Translate<P>,
+          M:  Isometry<P, P::Vect> + Translation<P::Vect> + Rotation<P::Vect>,
+          G1: SupportMap<P, M> + HasBoundingVolume<M, BoundingSphere<P>>,
+          G2: SupportMap<P, M> + HasBoundingVolume<M, BoundingSphere<P>> {
+    conservative_advancement::nonlinear_time_of_impact(m1, vel1, avel1, g1, m2, vel2, avel2, g2, options)
+}
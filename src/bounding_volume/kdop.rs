@@ -0,0 +1,205 @@
+use std::marker::PhantomData;
+use na::{Real, Unit};
+use na;
+use math::{Point, Vector, Isometry};
+use shape::SupportMap;
+use bounding_volume::BoundingVolume;
+
+/// A fixed set of slab axes defining a discrete-oriented-polytope.
+///
+/// Each axis covers both `axes()[i]` and its negation, so `Self::NUM_AXES` directions
+/// make up a `2 * Self::NUM_AXES`-DOP.
+pub trait KDOPAxes<N: Real> {
+    /// The number of independent slab axes.
+    const NUM_AXES: usize;
+
+    /// The direction of each slab axis.
+    fn axes() -> Vec<Unit<Vector<N>>>;
+}
+
+/// The axes of the classical 3D 14-DOP: the 3 coordinate axes plus the 4 cube diagonals.
+#[cfg(feature = "dim3")]
+pub struct KDOP14Axes;
+
+#[cfg(feature = "dim3")]
+impl<N: Real> KDOPAxes<N> for KDOP14Axes {
+    const NUM_AXES: usize = 7;
+
+    fn axes() -> Vec<Unit<Vector<N>>> {
+        let _1: N = na::one();
+        let _0: N = na::zero();
+        let diag: N = na::convert(1.0f64 / 3.0f64.sqrt());
+
+        vec![
+            Unit::new_unchecked(Vector::new(_1, _0, _0)),
+            Unit::new_unchecked(Vector::new(_0, _1, _0)),
+            Unit::new_unchecked(Vector::new(_0, _0, _1)),
+            Unit::new_unchecked(Vector::new(diag, diag, diag)),
+            Unit::new_unchecked(Vector::new(diag, diag, -diag)),
+            Unit::new_unchecked(Vector::new(diag, -diag, diag)),
+            Unit::new_unchecked(Vector::new(diag, -diag, -diag)),
+        ]
+    }
+}
+
+/// A discrete-oriented-polytope (k-DOP) bounding volume.
+///
+/// Generalizes the `AABB` to an arbitrary fixed set of slab axes (`D::axes()`), trading a
+/// slightly more expensive overlap test for a tighter fit on shapes with diagonal
+/// extents, such as `Cone`, `Cylinder` and `Segment`.
+pub struct KDOP<N: Real, D> {
+    slabs: Vec<(N, N)>,
+    _axes: PhantomData<D>
+}
+
+impl<N: Real, D: KDOPAxes<N>> Clone for KDOP<N, D> {
+    fn clone(&self) -> Self {
+        KDOP { slabs: self.slabs.clone(), _axes: PhantomData }
+    }
+}
+
+impl<N: Real, D: KDOPAxes<N>> KDOP<N, D> {
+    /// Computes the `k`-DOP of `shape`, by evaluating its support function along each
+    /// slab axis and its negation.
+    pub fn new<G: ?Sized + SupportMap<N>>(m: &Isometry<N>, shape: &G) -> KDOP<N, D> {
+        let mut slabs = Vec::with_capacity(D::NUM_AXES);
+
+        for axis in D::axes() {
+            let neg_axis = Unit::new_unchecked(-axis.into_inner());
+
+            let pos = shape.support_point(m, &axis);
+            let neg = shape.support_point(m, &neg_axis);
+
+            let max = na::dot(axis.as_ref(), &pos.coords);
+            let min = na::dot(axis.as_ref(), &neg.coords);
+
+            slabs.push((min, max));
+        }
+
+        KDOP { slabs, _axes: PhantomData }
+    }
+
+    /// The squared distance from `pt` to `self`.
+    ///
+    /// `self` is the intersection of `2 * D::NUM_AXES` half-spaces (two per slab axis).
+    /// Unlike `AABB`, those half-space normals are not mutually orthogonal in general, so
+    /// the per-axis clamped-gap sum used by `AABB` does not give the true Euclidean
+    /// distance here: it would only be exact if the nearest point always sat at the
+    /// intersection of planes meeting at right angles. Instead this uses Dykstra's
+    /// alternating projection algorithm, which converges to the point of the half-space
+    /// intersection that is actually closest to `pt`, regardless of how the planes are
+    /// oriented relative to one another.
+    pub fn distance_to_point_squared(&self, pt: &Point<N>) -> N {
+        let mut normals = Vec::with_capacity(D::NUM_AXES * 2);
+        let mut bounds = Vec::with_capacity(D::NUM_AXES * 2);
+
+        for (axis, slab) in D::axes().iter().zip(self.slabs.iter()) {
+            normals.push(axis.into_inner());
+            bounds.push(slab.1);
+
+            normals.push(-axis.into_inner());
+            bounds.push(-slab.0);
+        }
+
+        let mut x: Point<N> = *pt;
+        let mut corrections: Vec<Vector<N>> = vec![na::zero(); normals.len()];
+        let eps: N = na::convert(1.0e-10f64);
+
+        for _ in 0 .. 64 {
+            let mut max_shift = na::zero();
+
+            for i in 0 .. normals.len() {
+                let shifted = x + corrections[i];
+                let projected = project_onto_halfspace(&shifted, &normals[i], bounds[i]);
+
+                corrections[i] = shifted - projected;
+                max_shift = na::sup(&max_shift, &na::norm_squared(&(projected - x)));
+                x = projected;
+            }
+
+            // This is a cheap pruning bound for the BVT, not an exact query: once a full
+            // pass over all the half-spaces barely moves `x`, further iterations won't
+            // meaningfully tighten the bound either.
+            if max_shift < eps {
+                break;
+            }
+        }
+
+        na::norm_squared(&(x - *pt))
+    }
+}
+
+/// Projects `p` onto the half-space `{ x | dot(normal, x) <= bound }`, leaving it
+/// unchanged if it already satisfies the constraint.
+fn project_onto_halfspace<N: Real>(p: &Point<N>, normal: &Vector<N>, bound: N) -> Point<N> {
+    let violation = na::dot(&p.coords, normal) - bound;
+
+    if violation <= na::zero() {
+        *p
+    } else {
+        *p + (-*normal * violation)
+    }
+}
+
+impl<N: Real, D: KDOPAxes<N>> BoundingVolume<N> for KDOP<N, D> {
+    #[inline]
+    fn intersects(&self, other: &KDOP<N, D>) -> bool {
+        self.slabs.iter().zip(other.slabs.iter())
+            .all(|(a, b)| a.0 <= b.1 && b.0 <= a.1)
+    }
+
+    #[inline]
+    fn contains(&self, other: &KDOP<N, D>) -> bool {
+        self.slabs.iter().zip(other.slabs.iter())
+            .all(|(a, b)| a.0 <= b.0 && b.1 <= a.1)
+    }
+
+    #[inline]
+    fn merge(&mut self, other: &KDOP<N, D>) {
+        for (a, b) in self.slabs.iter_mut().zip(other.slabs.iter()) {
+            a.0 = na::inf(&a.0, &b.0);
+            a.1 = na::sup(&a.1, &b.1);
+        }
+    }
+
+    #[inline]
+    fn merged(&self, other: &KDOP<N, D>) -> KDOP<N, D> {
+        let mut res = self.clone();
+        res.merge(other);
+        res
+    }
+
+    #[inline]
+    fn loosen(&mut self, margin: N) {
+        assert!(margin >= na::zero(), "The loosening margin must be positive.");
+
+        for slab in self.slabs.iter_mut() {
+            slab.0 = slab.0 - margin;
+            slab.1 = slab.1 + margin;
+        }
+    }
+
+    #[inline]
+    fn loosened(&self, margin: N) -> KDOP<N, D> {
+        let mut res = self.clone();
+        res.loosen(margin);
+        res
+    }
+
+    #[inline]
+    fn tighten(&mut self, margin: N) {
+        assert!(margin >= na::zero(), "The tightening margin must be positive.");
+
+        for slab in self.slabs.iter_mut() {
+            slab.0 = slab.0 + margin;
+            slab.1 = slab.1 - margin;
+        }
+    }
+
+    #[inline]
+    fn tightened(&self, margin: N) -> KDOP<N, D> {
+        let mut res = self.clone();
+        res.tighten(margin);
+        res
+    }
+}
@@ -0,0 +1,51 @@
+use na::Real;
+use bounding_volume::{HasBoundingVolume, KDOP, KDOP14Axes};
+#[cfg(feature = "dim3")]
+use shape::{Capsule, Cone, Cylinder, Triangle};
+#[cfg(feature = "dim3")]
+use shape::Segment;
+use math::Isometry;
+
+/// A 3D 14-DOP, tighter than an `AABB` for diagonally-extended shapes.
+#[cfg(feature = "dim3")]
+pub type Kdop14<N> = KDOP<N, KDOP14Axes>;
+
+#[cfg(feature = "dim3")]
+impl<N: Real> HasBoundingVolume<N, Kdop14<N>> for Cone<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> Kdop14<N> {
+        KDOP::new(m, self)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: Real> HasBoundingVolume<N, Kdop14<N>> for Cylinder<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> Kdop14<N> {
+        KDOP::new(m, self)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: Real> HasBoundingVolume<N, Kdop14<N>> for Capsule<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> Kdop14<N> {
+        KDOP::new(m, self)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: Real> HasBoundingVolume<N, Kdop14<N>> for Triangle<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> Kdop14<N> {
+        KDOP::new(m, self)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: Real> HasBoundingVolume<N, Kdop14<N>> for Segment<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> Kdop14<N> {
+        KDOP::new(m, self)
+    }
+}
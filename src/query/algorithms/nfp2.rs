@@ -0,0 +1,111 @@
+//! The 2D No-Fit Polygon for convex shapes.
+
+use na::Real;
+use na;
+use shape::ConvexPolygon;
+use math::{Point, Vector, Isometry};
+
+/// Computes the No-Fit Polygon (NFP) of a stationary convex shape `a` and an orbiting
+/// convex shape `b`.
+///
+/// The NFP is the locus traced by `b`'s reference point as `b` slides around `a` while
+/// remaining in touching contact: any placement of `b`'s reference point strictly outside
+/// the returned polygon is guaranteed collision-free with `a`, and any point on its
+/// boundary is a touching configuration. This is a direct primitive for 2D nesting and
+/// packing.
+///
+/// For two convex shapes the NFP is the Minkowski sum of `a` with the reflection of `b`
+/// through its reference point, computed here by the orbiting/edge-merging method: the
+/// edges of `a` and of reflected `b` are sorted by polar angle, edges sharing the same
+/// direction are merged into one, and the result is walked head-to-tail starting from the
+/// tail of each operand's own lowest-angle edge (the vertex the angle-sort itself starts
+/// from), so the sum lands at the right absolute position rather than just the right
+/// shape.
+///
+/// `ma` places `a` in world space; `b` is assumed centered on its own reference point.
+pub fn no_fit_polygon<N: Real>(ma: &Isometry<N>, a: &ConvexPolygon<N>, b: &ConvexPolygon<N>) -> ConvexPolygon<N> {
+    let pts_a: Vec<Point<N>> = a.points().iter().map(|p| ma * p).collect();
+    // The reflection of `b` through its reference point.
+    let pts_b: Vec<Point<N>> = b.points().iter().map(|p| Point::from_coordinates(-p.coords)).collect();
+
+    let edges_a = edges_of(&pts_a);
+    let edges_b = edges_of(&pts_b);
+
+    // The anchor is the sum of each operand's own reference vertex for the merge: the
+    // tail of the edge with the lowest polar angle, i.e. the vertex the angle-sort below
+    // will itself place first. Picking any other vertex (e.g. the lowest-y one) reorders
+    // the walk relative to the sort and yields the right shape at the wrong position.
+    let anchor_a = pts_a[min_angle_edge(&edges_a)];
+    let anchor_b = pts_b[min_angle_edge(&edges_b)];
+
+    let mut edges: Vec<Vector<N>> = edges_a;
+    edges.extend(edges_b);
+    edges.sort_by(|e1, e2| polar_angle(e1).partial_cmp(&polar_angle(e2)).unwrap());
+
+    // Edges of equal direction from `a` and from reflected `b` sort next to each other;
+    // merge them into a single edge so the walk doesn't emit collinear duplicate
+    // vertices (which would otherwise happen for any two shapes sharing an edge
+    // direction, e.g. two axis-aligned rectangles).
+    let merged = merge_parallel_edges(edges);
+
+    let mut vertices = Vec::with_capacity(merged.len());
+    let mut curr = Point::from_coordinates(anchor_a.coords + anchor_b.coords);
+    vertices.push(curr);
+
+    for e in &merged[.. merged.len() - 1] {
+        curr = curr + *e;
+        vertices.push(curr);
+    }
+
+    ConvexPolygon::try_from_points(&vertices)
+        .expect("the no-fit polygon of two convex shapes must itself be convex")
+}
+
+fn edges_of<N: Real>(pts: &[Point<N>]) -> Vec<Vector<N>> {
+    let n = pts.len();
+    (0 .. n).map(|i| pts[(i + 1) % n] - pts[i]).collect()
+}
+
+fn polar_angle<N: Real>(v: &Vector<N>) -> N {
+    v[1].atan2(v[0])
+}
+
+/// Index of the edge (and, equivalently, of its tail vertex) with the lowest polar angle.
+fn min_angle_edge<N: Real>(edges: &[Vector<N>]) -> usize {
+    let mut best = 0;
+
+    for i in 1 .. edges.len() {
+        if polar_angle(&edges[i]) < polar_angle(&edges[best]) {
+            best = i;
+        }
+    }
+
+    best
+}
+
+/// Merges consecutive edges that point in the same direction into a single edge, so a
+/// sorted sequence containing edges of equal direction from both operands doesn't turn
+/// into collinear duplicate vertices when walked.
+fn merge_parallel_edges<N: Real>(edges: Vec<Vector<N>>) -> Vec<Vector<N>> {
+    let mut merged: Vec<Vector<N>> = Vec::with_capacity(edges.len());
+
+    for e in edges {
+        let merge_with_last = merged.last().map_or(false, |last| same_direction(last, &e));
+
+        if merge_with_last {
+            let last = merged.last_mut().unwrap();
+            *last = *last + e;
+        } else {
+            merged.push(e);
+        }
+    }
+
+    merged
+}
+
+fn same_direction<N: Real>(a: &Vector<N>, b: &Vector<N>) -> bool {
+    let eps: N = na::convert(1.0e-10f64);
+    let cross = a[0] * b[1] - a[1] * b[0];
+
+    cross.abs() < eps && na::dot(a, b) > na::zero()
+}